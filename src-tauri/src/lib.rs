@@ -1,96 +1,519 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use serde::Serialize;
 use tauri::{
     menu::{MenuBuilder, MenuItemBuilder, PredefinedMenuItem, SubmenuBuilder, CheckMenuItemBuilder},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    AppHandle, Manager, RunEvent, WindowEvent,
+    AppHandle, Emitter, Manager, RunEvent, WindowEvent,
 };
 use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
+use tauri_plugin_log::{Target, TargetKind};
+use tauri_plugin_opener::OpenerExt;
+use tauri_plugin_process::ProcessExt;
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
 use tauri_plugin_shell::ShellExt;
-use tauri_plugin_shell::process::CommandChild;
+use tauri_plugin_updater::{Update, UpdaterExt};
 
-/// Port the sidecar server runs on.
+/// Preferred port for the sidecar server; used as the starting point for
+/// port resolution at startup (see `resolve_port`).
 const DEFAULT_PORT: u16 = 3141;
+/// How many ports above the preferred one to try before giving up; if none
+/// of them is free either, `resolve_port` returns an error.
+const PORT_SCAN_RANGE: u16 = 10;
+
+/// Resolved runtime port shared across the sidecar, readiness polling,
+/// report triggering and webview navigation. Managed as Tauri state once
+/// `resolve_port` has picked a free port at startup.
+#[derive(Clone, Copy)]
+struct ServerConfig {
+    port: u16,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct ConfigFile {
+    port: Option<u16>,
+    max_consecutive_failures: Option<u32>,
+}
+
+/// Parse the app's `config.json`, if one exists. Shared by every
+/// `*_override` helper so they agree on the file's location and on how a
+/// malformed file is reported.
+fn read_config_file(app: &AppHandle) -> Option<ConfigFile> {
+    let config_dir = app.path().app_config_dir().ok()?;
+    let contents = std::fs::read_to_string(config_dir.join("config.json")).ok()?;
+    match serde_json::from_str::<ConfigFile>(&contents) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            log::warn!("Ignoring malformed config.json: {}", e);
+            None
+        }
+    }
+}
+
+/// Read a port override from `VWORK_PORT` or the `port` field of the app's
+/// `config.json`, preferring the environment variable.
+fn port_override(app: &AppHandle) -> Option<u16> {
+    if let Ok(value) = std::env::var("VWORK_PORT") {
+        match value.parse::<u16>() {
+            Ok(port) => return Some(port),
+            Err(e) => log::warn!("Ignoring invalid VWORK_PORT value {:?}: {}", value, e),
+        }
+    }
+
+    read_config_file(app).and_then(|config| config.port)
+}
+
+/// Read a max-consecutive-failures override from `VWORK_MAX_CONSECUTIVE_FAILURES`
+/// or the `max_consecutive_failures` field of the app's `config.json`,
+/// preferring the environment variable.
+fn max_consecutive_failures_override(app: &AppHandle) -> Option<u32> {
+    if let Ok(value) = std::env::var("VWORK_MAX_CONSECUTIVE_FAILURES") {
+        match value.parse::<u32>() {
+            Ok(n) => return Some(n),
+            Err(e) => log::warn!(
+                "Ignoring invalid VWORK_MAX_CONSECUTIVE_FAILURES value {:?}: {}",
+                value, e
+            ),
+        }
+    }
+
+    read_config_file(app).and_then(|config| config.max_consecutive_failures)
+}
+
+/// Find a free TCP port, trying `preferred` first and then scanning the
+/// next `PORT_SCAN_RANGE` ports above it. Returns `None` if none of them
+/// are free.
+fn find_free_port(preferred: u16) -> Option<u16> {
+    (preferred..=preferred.saturating_add(PORT_SCAN_RANGE))
+        .find(|&port| std::net::TcpListener::bind(("127.0.0.1", port)).is_ok())
+}
+
+/// Resolve the port the sidecar should run on: an override (env var or
+/// config file) falling back to `DEFAULT_PORT`, probed for availability so a
+/// second instance or an unrelated process holding the port doesn't cause a
+/// silent dead launch. Errors out (rather than silently handing back a busy
+/// port) if the whole scan range is occupied.
+fn resolve_port(app: &AppHandle) -> Result<u16, String> {
+    let preferred = port_override(app).unwrap_or(DEFAULT_PORT);
+    match find_free_port(preferred) {
+        Some(port) => {
+            if port != preferred {
+                log::warn!("Port {} is unavailable, falling back to {}", preferred, port);
+            }
+            Ok(port)
+        }
+        None => Err(format!(
+            "No free port found in {}..={} (preferred {})",
+            preferred,
+            preferred.saturating_add(PORT_SCAN_RANGE),
+            preferred
+        )),
+    }
+}
+
+/// Initial delay before the first respawn attempt after an unexpected exit.
+const RESTART_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Ceiling on the exponential respawn backoff.
+const RESTART_MAX_DELAY: Duration = Duration::from_secs(30);
+/// If the sidecar stays up longer than this, treat the next crash as a fresh
+/// failure streak instead of piling onto the previous one.
+const RESTART_STABLE_AFTER: Duration = Duration::from_secs(60);
+/// Stop trying to respawn after this many consecutive failures, unless
+/// overridden (see `max_consecutive_failures_override`).
+const DEFAULT_MAX_CONSECUTIVE_FAILURES: u32 = 5;
+
+/// Observed run state of the supervised sidecar process, mirrored to the
+/// frontend via the `sidecar-state` event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SidecarRunState {
+    Starting,
+    Ready,
+    Crashed,
+    Stopped,
+}
+
+impl SidecarRunState {
+    fn as_str(self) -> &'static str {
+        match self {
+            SidecarRunState::Starting => "starting",
+            SidecarRunState::Ready => "ready",
+            SidecarRunState::Crashed => "crashed",
+            SidecarRunState::Stopped => "stopped",
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
+struct SidecarStatePayload {
+    state: &'static str,
+}
+
+/// Supervises the `vwork-server` sidecar: owns the child process, tracks its
+/// run state, and automatically respawns it with exponential backoff if it
+/// terminates unexpectedly. `stop()` flips `stopping` before killing the
+/// child so the monitor loop treats the resulting `Terminated` event as
+/// intentional instead of racing to respawn. `running` is a CAS-guarded
+/// single-flight lock: only the caller that wins `try_claim` may spawn a
+/// monitor loop, so two concurrent start/restart calls can't both end up
+/// supervising their own child process.
+struct SidecarSupervisor {
+    child: Mutex<Option<CommandChild>>,
+    state: Mutex<SidecarRunState>,
+    stopping: Mutex<bool>,
+    running: AtomicBool,
+}
+
+impl SidecarSupervisor {
+    fn new() -> Self {
+        Self {
+            child: Mutex::new(None),
+            state: Mutex::new(SidecarRunState::Stopped),
+            stopping: Mutex::new(false),
+            running: AtomicBool::new(false),
+        }
+    }
+
+    fn state(&self) -> SidecarRunState {
+        *self.state.lock().unwrap()
+    }
+
+    /// Atomically claim the right to run a monitor loop. Returns `false`
+    /// (without changing anything) if one is already running. A successful
+    /// claim also discards any `stopping` flag left over from a previous
+    /// loop that never got a chance to consume it (e.g. `stop()` was called
+    /// while nothing was running), so the freshly spawned loop doesn't
+    /// mistake its first real crash for an intentional stop.
+    fn try_claim(&self) -> bool {
+        let claimed = self
+            .running
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok();
+        if claimed {
+            *self.stopping.lock().unwrap() = false;
+        }
+        claimed
+    }
+
+    fn release(&self) {
+        self.running.store(false, Ordering::SeqCst);
+    }
+
+    fn set_state(&self, app: &AppHandle, new_state: SidecarRunState) {
+        *self.state.lock().unwrap() = new_state;
+        let _ = app.emit("sidecar-state", SidecarStatePayload { state: new_state.as_str() });
+    }
+
+    fn set_child(&self, child: Option<CommandChild>) {
+        *self.child.lock().unwrap() = child;
+    }
+
+    /// Mark the next termination as intentional, then kill the running
+    /// child (if any) and report the state as `Stopped` right away.
+    fn stop(&self, app: &AppHandle) {
+        *self.stopping.lock().unwrap() = true;
+        if let Some(child) = self.child.lock().unwrap().take() {
+            let _ = child.kill();
+        }
+        self.set_state(app, SidecarRunState::Stopped);
+    }
+
+    fn take_stopping(&self) -> bool {
+        let mut stopping = self.stopping.lock().unwrap();
+        std::mem::replace(&mut *stopping, false)
+    }
+}
+
+/// Initial delay between readiness poll attempts, doubled after each miss.
+const READINESS_POLL_BASE_DELAY: Duration = Duration::from_millis(200);
+/// Ceiling on the readiness poll backoff.
+const READINESS_POLL_MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// Why `wait_for_server` gave up polling for readiness.
+#[derive(Debug)]
+enum ReadinessError {
+    /// Gave up after the timeout elapsed with the port never accepting a
+    /// successful response — the server is (still) starting.
+    TimedOut,
+    /// The server accepted the connection but responded with a non-2xx
+    /// status, meaning it's up but still failing during init.
+    ServerError(reqwest::StatusCode),
+}
+
+impl std::fmt::Display for ReadinessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadinessError::TimedOut => write!(f, "VWork server did not become ready in time"),
+            ReadinessError::ServerError(status) => {
+                write!(f, "VWork server responded with an error ({})", status)
+            }
+        }
+    }
+}
 
-/// State holding the sidecar child process so we can kill it on exit.
-struct SidecarState(Mutex<Option<CommandChild>>);
+#[derive(Clone, Serialize)]
+struct ReadinessProgressPayload {
+    attempt: u32,
+    elapsed_ms: u64,
+}
 
-/// Wait for the VWork HTTP server to become ready by polling its config endpoint.
-fn wait_for_server(port: u16, timeout: Duration) -> Result<(), String> {
+/// Wait for the VWork HTTP server to become ready by polling its config
+/// endpoint until it returns a 2xx response, using capped exponential
+/// backoff between attempts. Emits `sidecar-ready-progress` at the start of
+/// each attempt so the webview can show a loading/splash state.
+async fn wait_for_server(
+    app: &AppHandle,
+    port: u16,
+    timeout: Duration,
+) -> Result<(), ReadinessError> {
     let url = format!("http://127.0.0.1:{}/api/config", port);
-    let start = std::time::Instant::now();
-    let poll_interval = Duration::from_millis(200);
+    let client = reqwest::Client::new();
+    let start = Instant::now();
+    let mut delay = READINESS_POLL_BASE_DELAY;
+    let mut attempt: u32 = 0;
+    let mut last_status: Option<reqwest::StatusCode> = None;
 
     while start.elapsed() < timeout {
-        // Use a simple TCP connect check instead of full HTTP to avoid pulling in
-        // a blocking HTTP client at this stage.
-        if let Ok(stream) = std::net::TcpStream::connect_timeout(
-            &format!("127.0.0.1:{}", port).parse().unwrap(),
-            Duration::from_secs(1),
-        ) {
-            drop(stream);
-            // Server is accepting connections — give it a moment to finish init
-            std::thread::sleep(Duration::from_millis(300));
-            return Ok(());
+        attempt += 1;
+        let _ = app.emit(
+            "sidecar-ready-progress",
+            ReadinessProgressPayload {
+                attempt,
+                elapsed_ms: start.elapsed().as_millis() as u64,
+            },
+        );
+
+        match client.get(&url).timeout(Duration::from_secs(2)).send().await {
+            Ok(resp) if resp.status().is_success() => return Ok(()),
+            Ok(resp) => last_status = Some(resp.status()),
+            Err(_) => {
+                // Connection refused or request error — still starting, keep polling.
+            }
         }
-        std::thread::sleep(poll_interval);
+
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(READINESS_POLL_MAX_DELAY);
     }
 
-    Err(format!(
-        "VWork server did not start within {}s (tried {})",
-        timeout.as_secs(),
-        url
-    ))
+    Err(match last_status {
+        Some(status) => ReadinessError::ServerError(status),
+        None => ReadinessError::TimedOut,
+    })
 }
 
-/// Spawn the VWork sidecar binary.
-fn spawn_sidecar(app: &AppHandle) -> Result<CommandChild, String> {
+/// Spawn the VWork sidecar binary, returning the child handle and its event
+/// stream so the caller can forward output and detect termination.
+fn spawn_sidecar(
+    app: &AppHandle,
+) -> Result<(tauri::async_runtime::Receiver<CommandEvent>, CommandChild), String> {
+    let port = app
+        .try_state::<ServerConfig>()
+        .ok_or_else(|| "Server port was never resolved".to_string())?
+        .port;
     let shell = app.shell();
     let command = shell
         .sidecar("binaries/vwork-server")
         .map_err(|e| format!("Failed to create sidecar command: {}", e))?
-        .args(["serve", "--port", &DEFAULT_PORT.to_string()]);
+        .args(["serve", "--port", &port.to_string()]);
 
-    let (mut rx, child) = command
+    command
         .spawn()
-        .map_err(|e| format!("Failed to spawn sidecar: {}", e))?;
-
-    // Forward sidecar stderr to our stderr for debugging
-    tauri::async_runtime::spawn(async move {
-        use tauri_plugin_shell::process::CommandEvent;
-        while let Some(event) = rx.recv().await {
-            match event {
-                CommandEvent::Stderr(line) => {
-                    let text = String::from_utf8_lossy(&line);
-                    eprint!("{}", text);
+        .map_err(|e| format!("Failed to spawn sidecar: {}", e))
+}
+
+/// Forward a line of sidecar output into the log pipeline under the
+/// `sidecar` target. Lines that start with a recognizable level prefix
+/// (`ERROR`, `WARN`, `INFO`, `DEBUG`, `TRACE`) are logged at that level so
+/// the sidecar's own log formatting is preserved; everything else falls
+/// back to `default_level`.
+fn log_sidecar_line(line: &str, default_level: log::Level) {
+    let trimmed = line.trim_end();
+    let prefix = trimmed
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or("")
+        .to_ascii_uppercase();
+
+    let level = match prefix.as_str() {
+        "ERROR" => log::Level::Error,
+        "WARN" | "WARNING" => log::Level::Warn,
+        "INFO" => log::Level::Info,
+        "DEBUG" => log::Level::Debug,
+        "TRACE" => log::Level::Trace,
+        _ => default_level,
+    };
+
+    log::log!(target: "sidecar", level, "{}", trimmed);
+}
+
+/// Run the supervised sidecar lifecycle: spawn, forward its output, and on
+/// unexpected termination respawn with exponential backoff (reset once the
+/// process has stayed up for `RESTART_STABLE_AFTER`), giving up after
+/// `max_consecutive_failures_override` (or `DEFAULT_MAX_CONSECUTIVE_FAILURES`)
+/// crashes in a row. Exits without respawning as soon as
+/// `SidecarSupervisor::stop` has flagged the shutdown as intentional.
+///
+/// The caller must have already won `SidecarSupervisor::try_claim` before
+/// spawning this; it releases the claim on exit.
+async fn supervise_sidecar(app: AppHandle) {
+    let supervisor = app.state::<SidecarSupervisor>();
+
+    let max_consecutive_failures =
+        max_consecutive_failures_override(&app).unwrap_or(DEFAULT_MAX_CONSECUTIVE_FAILURES);
+    let mut backoff = RESTART_BASE_DELAY;
+    let mut consecutive_failures: u32 = 0;
+
+    'supervise: loop {
+        // A stop that arrived while waiting out the backoff ends the loop here
+        // instead of spawning a fresh child.
+        if supervisor.take_stopping() {
+            break 'supervise;
+        }
+
+        supervisor.set_state(&app, SidecarRunState::Starting);
+
+        let (mut rx, child) = match spawn_sidecar(&app) {
+            Ok(pair) => pair,
+            Err(e) => {
+                log::error!("Failed to spawn sidecar: {}", e);
+                if supervisor.take_stopping() {
+                    break 'supervise;
                 }
-                CommandEvent::Stdout(line) => {
-                    let text = String::from_utf8_lossy(&line);
-                    eprint!("[sidecar stdout] {}", text);
+                consecutive_failures += 1;
+                if consecutive_failures >= max_consecutive_failures {
+                    log::error!(
+                        "Sidecar failed to start {} times in a row, giving up",
+                        consecutive_failures
+                    );
+                    supervisor.set_state(&app, SidecarRunState::Crashed);
+                    break 'supervise;
                 }
-                CommandEvent::Error(err) => {
-                    eprintln!("[sidecar error] {}", err);
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(RESTART_MAX_DELAY);
+                continue 'supervise;
+            }
+        };
+
+        supervisor.set_child(Some(child));
+        let started_at = Instant::now();
+
+        loop {
+            match rx.recv().await {
+                Some(CommandEvent::Stderr(line)) => {
+                    log_sidecar_line(&String::from_utf8_lossy(&line), log::Level::Error);
+                }
+                Some(CommandEvent::Stdout(line)) => {
+                    log_sidecar_line(&String::from_utf8_lossy(&line), log::Level::Info);
                 }
-                CommandEvent::Terminated(status) => {
-                    eprintln!("[sidecar] process exited: {:?}", status);
+                Some(CommandEvent::Error(err)) => {
+                    log::error!(target: "sidecar", "{}", err);
+                }
+                Some(CommandEvent::Terminated(status)) => {
+                    log::info!(target: "sidecar", "process exited: {:?}", status);
+                    supervisor.set_child(None);
+                    break;
+                }
+                Some(_) => {}
+                None => {
+                    supervisor.set_child(None);
                     break;
                 }
-                _ => {}
             }
         }
-    });
 
-    Ok(child)
+        if supervisor.take_stopping() {
+            break 'supervise;
+        }
+
+        supervisor.set_state(&app, SidecarRunState::Crashed);
+
+        if started_at.elapsed() > RESTART_STABLE_AFTER {
+            backoff = RESTART_BASE_DELAY;
+            consecutive_failures = 0;
+        }
+
+        consecutive_failures += 1;
+        if consecutive_failures >= max_consecutive_failures {
+            log::error!(
+                "Sidecar crashed {} times in a row, giving up",
+                consecutive_failures
+            );
+            break 'supervise;
+        }
+
+        log::warn!(
+            "Sidecar terminated unexpectedly, restarting in {:?} (attempt {})",
+            backoff, consecutive_failures
+        );
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(RESTART_MAX_DELAY);
+    }
+
+    supervisor.release();
 }
 
-/// Kill the sidecar process gracefully.
-fn kill_sidecar(state: &SidecarState) {
-    if let Ok(mut guard) = state.0.lock() {
-        if let Some(child) = guard.take() {
-            let _ = child.kill();
+/// Kill the sidecar process and mark the supervisor as intentionally
+/// stopped so the monitor loop does not try to respawn it.
+fn kill_sidecar(app: &AppHandle) {
+    if let Some(supervisor) = app.try_state::<SidecarSupervisor>() {
+        supervisor.stop(app);
+    }
+}
+
+/// Start the sidecar under supervision. No-op (with an error) if it's
+/// already running.
+#[tauri::command]
+async fn start_server(app: AppHandle) -> Result<(), String> {
+    let supervisor = app.state::<SidecarSupervisor>();
+    if !supervisor.try_claim() {
+        return Err("Sidecar is already running".into());
+    }
+    tauri::async_runtime::spawn(supervise_sidecar(app.clone()));
+    Ok(())
+}
+
+/// Query the sidecar's current run state, for the frontend to reflect
+/// without having to track the `sidecar-state` event since launch.
+#[tauri::command]
+fn sidecar_state(app: AppHandle) -> &'static str {
+    app.state::<SidecarSupervisor>().state().as_str()
+}
+
+/// Stop the sidecar and leave it stopped until `start_server` or
+/// `restart_server` is called again.
+#[tauri::command]
+async fn stop_server(app: AppHandle) -> Result<(), String> {
+    kill_sidecar(&app);
+    Ok(())
+}
+
+/// Stop the sidecar and immediately bring up a fresh instance under
+/// supervision.
+#[tauri::command]
+async fn restart_server(app: AppHandle) -> Result<(), String> {
+    let supervisor = app.state::<SidecarSupervisor>();
+    kill_sidecar(&app);
+
+    // Wait for the old monitor loop to notice the intentional stop, release
+    // its claim and exit, then claim it ourselves before spawning a new one —
+    // `try_claim` is the actual single-flight guard; this loop just avoids
+    // spinning on it forever if the old loop never lets go.
+    let mut claimed = false;
+    for _ in 0..100 {
+        if supervisor.try_claim() {
+            claimed = true;
+            break;
         }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    if !claimed {
+        return Err("Timed out waiting for the previous sidecar monitor to exit".into());
     }
+
+    tauri::async_runtime::spawn(supervise_sidecar(app.clone()));
+    Ok(())
 }
 
 /// Build the system tray menu and icon.
@@ -98,9 +521,16 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     let open = MenuItemBuilder::with_id("open", "Open VWork").build(app)?;
     let generate = MenuItemBuilder::with_id("generate_report", "Generate Report").build(app)?;
     let sep1 = PredefinedMenuItem::separator(app)?;
+    let restart_server_item =
+        MenuItemBuilder::with_id("restart_server", "Restart Server").build(app)?;
+    let show_logs = MenuItemBuilder::with_id("show_logs", "Show Logs").build(app)?;
+    let sep2 = PredefinedMenuItem::separator(app)?;
+    let check_for_updates_item =
+        MenuItemBuilder::with_id("check_for_updates", "Check for Updates…").build(app)?;
+    let sep3 = PredefinedMenuItem::separator(app)?;
     let autolaunch = CheckMenuItemBuilder::with_id("autolaunch", "Launch at Login")
         .build(app)?;
-    let sep2 = PredefinedMenuItem::separator(app)?;
+    let sep4 = PredefinedMenuItem::separator(app)?;
     let quit = MenuItemBuilder::with_id("quit", "Quit VWork").build(app)?;
 
     // Check current autostart state
@@ -112,7 +542,19 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let menu = MenuBuilder::new(app)
-        .items(&[&open, &generate, &sep1, &autolaunch, &sep2, &quit])
+        .items(&[
+            &open,
+            &generate,
+            &sep1,
+            &restart_server_item,
+            &show_logs,
+            &sep2,
+            &check_for_updates_item,
+            &sep3,
+            &autolaunch,
+            &sep4,
+            &quit,
+        ])
         .build()?;
 
     let app_handle = app.clone();
@@ -136,6 +578,29 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                         trigger_report(&app).await;
                     });
                 }
+                "restart_server" => {
+                    let app = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        let _ = restart_server(app).await;
+                    });
+                }
+                "show_logs" => {
+                    match app.path().app_log_dir() {
+                        Ok(dir) => {
+                            let log_file = dir.join(format!("{}.log", app.package_info().name));
+                            if let Err(e) = app.opener().reveal_item_in_dir(log_file) {
+                                log::error!("Failed to reveal log file: {}", e);
+                            }
+                        }
+                        Err(e) => log::error!("Failed to resolve log directory: {}", e),
+                    }
+                }
+                "check_for_updates" => {
+                    let app = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        check_for_updates(&app, false).await;
+                    });
+                }
                 "autolaunch" => {
                     let autostart = app.autolaunch();
                     if let Ok(enabled) = autostart.is_enabled() {
@@ -150,9 +615,7 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                 }
                 "quit" => {
                     // Kill sidecar before quitting
-                    if let Some(state) = app.try_state::<SidecarState>() {
-                        kill_sidecar(&state);
-                    }
+                    kill_sidecar(app);
                     app.exit(0);
                 }
                 _ => {}
@@ -185,10 +648,13 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
 fn setup_native_menu(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     let about = PredefinedMenuItem::about(app, Some("About VWork"), None)?;
     let sep = PredefinedMenuItem::separator(app)?;
+    let check_for_updates_item =
+        MenuItemBuilder::with_id("check_for_updates", "Check for Updates…").build(app)?;
+    let sep_updates = PredefinedMenuItem::separator(app)?;
     let quit_item = PredefinedMenuItem::quit(app, Some("Quit VWork"))?;
 
     let app_menu = SubmenuBuilder::new(app, "VWork")
-        .items(&[&about, &sep, &quit_item])
+        .items(&[&about, &sep, &check_for_updates_item, &sep_updates, &quit_item])
         .build()?;
 
     let copy = PredefinedMenuItem::copy(app, None)?;
@@ -215,13 +681,27 @@ fn setup_native_menu(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>>
 
     app.set_menu(menu)?;
 
+    app.on_menu_event(move |app, event| {
+        if event.id().as_ref() == "check_for_updates" {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                check_for_updates(&app, false).await;
+            });
+        }
+    });
+
     Ok(())
 }
 
 /// Trigger report generation via the sidecar's HTTP API and show a notification.
 async fn trigger_report(app: &AppHandle) {
+    let Some(port) = app.try_state::<ServerConfig>().map(|c| c.port) else {
+        log::error!("Cannot trigger report: server port was never resolved");
+        return;
+    };
+
     // Fire-and-forget POST to the sidecar
-    let url = format!("http://127.0.0.1:{}/api/report/run", DEFAULT_PORT);
+    let url = format!("http://127.0.0.1:{}/api/report/run", port);
 
     let result: Result<(), String> = async {
         let client = reqwest::Client::new();
@@ -255,7 +735,7 @@ async fn trigger_report(app: &AppHandle) {
             }
         }
         Err(e) => {
-            eprintln!("[vwork] Failed to trigger report: {}", e);
+            log::error!("Failed to trigger report: {}", e);
             #[cfg(desktop)]
             {
                 use tauri_plugin_notification::NotificationExt;
@@ -270,6 +750,124 @@ async fn trigger_report(app: &AppHandle) {
     }
 }
 
+/// Whether the silent startup update check should run. Set
+/// `VWORK_DISABLE_AUTO_UPDATE=1` to turn it off.
+fn should_auto_check_for_updates() -> bool {
+    std::env::var("VWORK_DISABLE_AUTO_UPDATE")
+        .map(|v| v != "1")
+        .unwrap_or(true)
+}
+
+/// Check the configured update endpoint and, if a newer version is
+/// available, notify the user and confirm before installing it. `silent`
+/// suppresses the "up to date" / failure notifications so the automatic
+/// startup check doesn't nag the user; the result is still logged either way.
+async fn check_for_updates(app: &AppHandle, silent: bool) {
+    let updater = match app.updater() {
+        Ok(updater) => updater,
+        Err(e) => {
+            log::error!("Failed to initialize updater: {}", e);
+            return;
+        }
+    };
+
+    let update = match updater.check().await {
+        Ok(Some(update)) => update,
+        Ok(None) => {
+            log::info!("VWork is up to date");
+            if !silent {
+                #[cfg(desktop)]
+                {
+                    use tauri_plugin_notification::NotificationExt;
+                    let _ = app
+                        .notification()
+                        .builder()
+                        .title("VWork")
+                        .body("You're up to date.")
+                        .show();
+                }
+            }
+            return;
+        }
+        Err(e) => {
+            log::error!("Update check failed: {}", e);
+            if !silent {
+                #[cfg(desktop)]
+                {
+                    use tauri_plugin_notification::NotificationExt;
+                    let _ = app
+                        .notification()
+                        .builder()
+                        .title("VWork")
+                        .body(format!("Update check failed: {}", e))
+                        .show();
+                }
+            }
+            return;
+        }
+    };
+
+    log::info!("Update available: {}", update.version);
+    let _ = app.emit("update-available", update.version.clone());
+
+    #[cfg(desktop)]
+    {
+        use tauri_plugin_notification::NotificationExt;
+        let _ = app
+            .notification()
+            .builder()
+            .title("VWork")
+            .body(format!("Version {} is available", update.version))
+            .show();
+    }
+
+    let app_for_install = app.clone();
+    let version = update.version.clone();
+    app.dialog()
+        .message(format!(
+            "VWork {} is available. Install and restart now?",
+            version
+        ))
+        .title("Update Available")
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            "Install & Restart".into(),
+            "Later".into(),
+        ))
+        .show(move |confirmed| {
+            if !confirmed {
+                return;
+            }
+            let app = app_for_install.clone();
+            tauri::async_runtime::spawn(async move {
+                install_update(app, update).await;
+            });
+        });
+}
+
+/// Stop the sidecar, download and install the update, then relaunch.
+async fn install_update(app: AppHandle, update: Update) {
+    // Terminate the running vwork-server child before the binary it was
+    // spawned from gets replaced out from under it.
+    kill_sidecar(&app);
+
+    if let Err(e) = update.download_and_install(|_, _| {}, || {}).await {
+        log::error!("Failed to install update: {}", e);
+        #[cfg(desktop)]
+        {
+            use tauri_plugin_notification::NotificationExt;
+            let _ = app
+                .notification()
+                .builder()
+                .title("VWork")
+                .body(format!("Update failed: {}", e))
+                .show();
+        }
+        return;
+    }
+
+    app.restart();
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -279,39 +877,121 @@ pub fn run() {
             MacosLauncher::LaunchAgent,
             None,
         ))
-        .manage(SidecarState(Mutex::new(None)))
+        .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_process::init())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(
+            tauri_plugin_log::Builder::new()
+                .targets([
+                    Target::new(TargetKind::LogDir { file_name: None }),
+                    Target::new(TargetKind::Stdout),
+                    Target::new(TargetKind::Webview),
+                ])
+                .max_file_size(10_000_000)
+                .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepOne)
+                .level(log::LevelFilter::Info)
+                .build(),
+        )
+        .manage(SidecarSupervisor::new())
+        .invoke_handler(tauri::generate_handler![
+            start_server,
+            stop_server,
+            restart_server,
+            sidecar_state
+        ])
         .setup(|app| {
             let handle = app.handle().clone();
 
-            // Spawn the VWork sidecar server
-            eprintln!("[vwork] Starting sidecar on port {}...", DEFAULT_PORT);
-            match spawn_sidecar(&handle) {
-                Ok(child) => {
-                    // Store the child so we can kill it later
-                    let state = handle.state::<SidecarState>();
-                    *state.0.lock().unwrap() = Some(child);
-                    eprintln!("[vwork] Sidecar spawned, waiting for server...");
-                }
+            let port = match resolve_port(&handle) {
+                Ok(port) => port,
                 Err(e) => {
-                    eprintln!("[vwork] Failed to spawn sidecar: {}", e);
-                    // Continue anyway — user can still use the app if server starts separately
+                    log::error!("Failed to resolve a port for the sidecar: {}", e);
+                    #[cfg(desktop)]
+                    {
+                        use tauri_plugin_notification::NotificationExt;
+                        let _ = handle
+                            .notification()
+                            .builder()
+                            .title("VWork")
+                            .body(format!("VWork could not start: {}", e))
+                            .show();
+                    }
+                    // No usable port — set up the tray so the user can still quit,
+                    // but don't spawn a sidecar that would just fail on the same
+                    // port conflict.
+                    setup_tray(&handle)?;
+                    return Ok(());
                 }
-            }
+            };
+            handle.manage(ServerConfig { port });
+
+            // Spawn and supervise the VWork sidecar server
+            log::info!("Starting sidecar on port {}...", port);
+            handle.state::<SidecarSupervisor>().try_claim();
+            tauri::async_runtime::spawn(supervise_sidecar(handle.clone()));
 
-            // Wait for server in a background thread, then load the URL
+            // Wait for server readiness, then load the URL
             let handle2 = handle.clone();
-            std::thread::spawn(move || {
-                match wait_for_server(DEFAULT_PORT, Duration::from_secs(15)) {
+            tauri::async_runtime::spawn(async move {
+                match wait_for_server(&handle2, port, Duration::from_secs(15)).await {
                     Ok(()) => {
-                        eprintln!("[vwork] Server is ready!");
+                        log::info!("Server is ready!");
+                        if let Some(supervisor) = handle2.try_state::<SidecarSupervisor>() {
+                            supervisor.set_state(&handle2, SidecarRunState::Ready);
+                        }
                         // Navigate the webview to the server URL
                         if let Some(w) = handle2.get_webview_window("main") {
-                            let url = format!("http://localhost:{}", DEFAULT_PORT);
+                            let url = format!("http://localhost:{}", port);
                             let _ = w.navigate(url.parse().unwrap());
                         }
+
+                        // Silently check for updates once the server is up
+                        if should_auto_check_for_updates() {
+                            let handle3 = handle2.clone();
+                            tauri::async_runtime::spawn(async move {
+                                check_for_updates(&handle3, true).await;
+                            });
+                        }
                     }
                     Err(e) => {
-                        eprintln!("[vwork] {}", e);
+                        log::error!("{}", e);
+                        if let Some(supervisor) = handle2.try_state::<SidecarSupervisor>() {
+                            supervisor.set_state(&handle2, SidecarRunState::Crashed);
+                        }
+
+                        #[cfg(desktop)]
+                        {
+                            use tauri_plugin_notification::NotificationExt;
+                            let _ = handle2
+                                .notification()
+                                .builder()
+                                .title("VWork")
+                                .body(format!("Server failed to start: {}", e))
+                                .show();
+                        }
+
+                        let handle3 = handle2.clone();
+                        handle2
+                            .dialog()
+                            .message(format!("VWork's server didn't start: {}", e))
+                            .title("Server Not Ready")
+                            .buttons(MessageDialogButtons::OkCancelCustom(
+                                "Retry".into(),
+                                "Dismiss".into(),
+                            ))
+                            .show(move |confirmed| {
+                                if !confirmed {
+                                    return;
+                                }
+                                let app = handle3.clone();
+                                tauri::async_runtime::spawn(async move {
+                                    // The sidecar may still be alive but stuck (port open,
+                                    // `/api/config` erroring) — restart to actually replace it
+                                    // rather than start_server, which no-ops while it's running.
+                                    let _ = restart_server(app).await;
+                                });
+                            });
                     }
                 }
             });
@@ -336,9 +1016,7 @@ pub fn run() {
         .run(|app, event| {
             if let RunEvent::ExitRequested { .. } = event {
                 // Kill sidecar on exit
-                if let Some(state) = app.try_state::<SidecarState>() {
-                    kill_sidecar(&state);
-                }
+                kill_sidecar(app);
             }
         });
 }